@@ -4,8 +4,12 @@ use std::fs;
 use std::io::{prelude::*};
 use std::process::Command;
 use std::path::Path;
+use std::collections::HashMap;
 use serde_derive::{Deserialize, Serialize};
 use clap::{command, Parser, ValueEnum};
+use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
+
+mod tui;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ValueEnum)]
 enum Priority {
@@ -14,11 +18,38 @@ enum Priority {
     High,
     Urgent
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ValueEnum)]
+enum Format {
+    Md,
+    Todotxt,
+}
+
+impl Format {
+    fn from_extension(file_path: &str) -> Format {
+        if file_path.ends_with(".txt") {
+            Format::Todotxt
+        } else {
+            Format::Md
+        }
+    }
+}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TodoItem {
+    id: u64,
     item: String,
     status: bool,
     priority: Priority,
+    due: Option<NaiveDate>,
+    depends_on: Vec<u64>,
+    time_log: Vec<TimeEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TimeEntry {
+    logged_date: NaiveDate,
+    hours: u16,
+    minutes: u16,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +62,96 @@ struct TodoManager {
     file_path: String,
     current_todo_spaces: Vec<TodoSpace>,
     current_space: Option<String>,
+    format: Option<Format>,
+    undo_stack: Vec<Action>,
+    redo_stack: Vec<Action>,
+}
+
+const UNDO_HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Action {
+    Insert { space_name: String, index: usize, todo: TodoItem },
+    Remove { space_name: String, index: usize, todo: TodoItem },
+    Toggle { space_name: String, index: usize },
+    SetDeps { space_name: String, index: usize, old: Vec<u64>, new: Vec<u64> },
+    LogTime { space_name: String, index: usize, entry: TimeEntry },
+    UnlogTime { space_name: String, index: usize, entry: TimeEntry },
+}
+
+impl Action {
+    fn inverse(&self) -> Action {
+        match self {
+            Action::Insert { space_name, index, todo } => Action::Remove {
+                space_name: space_name.clone(), index: *index, todo: todo.clone(),
+            },
+            Action::Remove { space_name, index, todo } => Action::Insert {
+                space_name: space_name.clone(), index: *index, todo: todo.clone(),
+            },
+            Action::Toggle { space_name, index } => Action::Toggle {
+                space_name: space_name.clone(), index: *index,
+            },
+            Action::SetDeps { space_name, index, old, new } => Action::SetDeps {
+                space_name: space_name.clone(), index: *index, old: new.clone(), new: old.clone(),
+            },
+            Action::LogTime { space_name, index, entry } => Action::UnlogTime {
+                space_name: space_name.clone(), index: *index, entry: entry.clone(),
+            },
+            Action::UnlogTime { space_name, index, entry } => Action::LogTime {
+                space_name: space_name.clone(), index: *index, entry: entry.clone(),
+            },
+        }
+    }
+}
+
+// Pure reducer: applies an action to the todo-space state, no I/O.
+fn reduce(spaces: &mut Vec<TodoSpace>, action: &Action) {
+    match action {
+        Action::Insert { space_name, index, todo } => {
+            let space_idx = spaces.iter().position(|s| &s.name == space_name)
+                .unwrap_or_else(|| {
+                    spaces.push(TodoSpace { name: space_name.clone(), todos: Vec::new() });
+                    spaces.len() - 1
+                });
+            let at = (*index).min(spaces[space_idx].todos.len());
+            spaces[space_idx].todos.insert(at, todo.clone());
+        },
+        Action::Remove { space_name, index, .. } => {
+            if let Some(space) = spaces.iter_mut().find(|s| &s.name == space_name) {
+                if *index < space.todos.len() {
+                    space.todos.remove(*index);
+                }
+            }
+        },
+        Action::Toggle { space_name, index } => {
+            if let Some(space) = spaces.iter_mut().find(|s| &s.name == space_name) {
+                if let Some(todo) = space.todos.get_mut(*index) {
+                    todo.status = !todo.status;
+                }
+            }
+        },
+        Action::SetDeps { space_name, index, new, .. } => {
+            if let Some(space) = spaces.iter_mut().find(|s| &s.name == space_name) {
+                if let Some(todo) = space.todos.get_mut(*index) {
+                    todo.depends_on = new.clone();
+                }
+            }
+        },
+        Action::LogTime { space_name, index, entry } => {
+            if let Some(space) = spaces.iter_mut().find(|s| &s.name == space_name) {
+                if let Some(todo) = space.todos.get_mut(*index) {
+                    todo.time_log.push(entry.clone());
+                }
+            }
+        },
+        Action::UnlogTime { space_name, index, .. } => {
+            if let Some(space) = spaces.iter_mut().find(|s| &s.name == space_name) {
+                if let Some(todo) = space.todos.get_mut(*index) {
+                    todo.time_log.pop();
+                }
+            }
+        },
+    }
 }
 
 impl Priority {
@@ -55,6 +176,23 @@ impl Priority {
             Priority::Low => "{LOW}"
         }
     }
+    fn from_todotxt_letter(letter: char) -> Priority {
+        match letter {
+            'A' => Priority::Urgent,
+            'B' => Priority::High,
+            'C' => Priority::Medium,
+            'D' => Priority::Low,
+            _ => Priority::Medium,
+        }
+    }
+    fn to_todotxt_letter(&self) -> char {
+        match self {
+            Priority::Urgent => 'A',
+            Priority::High => 'B',
+            Priority::Medium => 'C',
+            Priority::Low => 'D',
+        }
+    }
 }
 
 
@@ -63,55 +201,158 @@ enum StatusFilter {
     All,
     Completed,
     Pending,
+    Overdue,
+    Today,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum SortKey {
+    Due,
 }
 
 impl TodoManager {
 
-    fn new(file_path: String) -> Self {
+    fn new(file_path: String, format: Option<Format>) -> Self {
         if let Some(parent) = Path::new(&file_path).parent() {
             fs::create_dir_all(&parent).expect("Could not create config directory");
         };
         let mut manager = TodoManager {
             file_path,
             current_todo_spaces: Vec::new(),
-            current_space: None
+            current_space: None,
+            format,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
         };
         if manager.load_todos().is_err() {
             manager.current_todo_spaces.push(TodoSpace { name: "Default".to_string(), todos: Vec::new() });
             manager.save_todos().expect("Could not create initial todo file");
         }
+        manager.load_history();
         manager
     }
 
-    fn add_todo(&mut self, todo_item: String, space_name: Option<String>, priority: Option<Priority>) -> Result<(), String> {
+    fn resolve_format(&self) -> Format {
+        self.format.unwrap_or_else(|| Format::from_extension(&self.file_path))
+    }
+
+    fn next_id(&self) -> u64 {
+        self.current_todo_spaces.iter()
+            .flat_map(|space| space.todos.iter())
+            .map(|todo| todo.id)
+            .max()
+            .map_or(1, |max_id| max_id + 1)
+    }
+
+    fn history_path(&self) -> String {
+        format!("{}.history.json", self.file_path)
+    }
+
+    fn load_history(&mut self) {
+        if let Ok(content) = fs::read_to_string(self.history_path()) {
+            if let Ok((undo_stack, redo_stack)) = serde_json::from_str::<(Vec<Action>, Vec<Action>)>(&content) {
+                self.undo_stack = undo_stack;
+                self.redo_stack = redo_stack;
+            }
+        }
+    }
+
+    fn save_history(&self) -> Result<(), String> {
+        let content = serde_json::to_string(&(&self.undo_stack, &self.redo_stack)).map_err(|e| e.to_string())?;
+        fs::write(self.history_path(), content).map_err(|e| e.to_string())
+    }
+
+    // Applies `action` through the reducer, records it for undo, and persists.
+    fn dispatch(&mut self, action: Action) -> Result<(), String> {
+        reduce(&mut self.current_todo_spaces, &action);
+        self.undo_stack.push(action);
+        if self.undo_stack.len() > UNDO_HISTORY_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+        self.save_todos()?;
+        self.save_history()
+    }
+
+    fn undo(&mut self) -> Result<(), String> {
+        let action = self.undo_stack.pop().ok_or_else(|| "Nothing to undo".to_string())?;
+        reduce(&mut self.current_todo_spaces, &action.inverse());
+        self.redo_stack.push(action);
+        self.save_todos()?;
+        self.save_history()
+    }
+
+    fn redo(&mut self) -> Result<(), String> {
+        let action = self.redo_stack.pop().ok_or_else(|| "Nothing to redo".to_string())?;
+        reduce(&mut self.current_todo_spaces, &action);
+        self.undo_stack.push(action);
+        self.save_todos()?;
+        self.save_history()
+    }
+
+    fn add_todo(&mut self, todo_item: String, space_name: Option<String>, priority: Option<Priority>, due: Option<NaiveDate>, depends_on: Vec<u64>) -> Result<(), String> {
         let space_name = space_name.unwrap_or_else(|| "Default".to_string());
         let priority = priority.unwrap_or(Priority::Medium);
+        let id = self.next_id();
+        let index = self.current_todo_spaces.iter()
+            .find(|space| space.name == space_name)
+            .map_or(0, |space| space.todos.len());
+        let todo = TodoItem { id, item: todo_item, status: false, priority, due, depends_on, time_log: Vec::new() };
+        self.dispatch(Action::Insert { space_name, index, todo })
+    }
 
-        // find or create the space     
-        let space_name_idx = self.current_todo_spaces.iter()
-            .position(|space| space.name == space_name)
-            .unwrap_or_else(|| {
-                // space doesnt exist, lets create it
-                self.current_todo_spaces.push(
-                    TodoSpace { name: space_name.clone(), todos: Vec::new() }
-                );
-                self.current_todo_spaces.len() - 1 //return the idx of the new space
-            });
-        self.current_todo_spaces[space_name_idx].todos.push(TodoItem{
-            item: todo_item,
-            status: false,
-            priority,
-            }); 
-        self.save_todos()?;
-        Ok(())
+    fn set_dependencies(&mut self, index: usize, space_name: Option<String>, depends_on: Vec<u64>) -> Result<(), String> {
+        let space_name = space_name.unwrap_or_else(|| "Default".to_string());
+        let space = self.current_todo_spaces.iter().find(|s| s.name == space_name)
+            .ok_or_else(|| "Space not found".to_string())?;
+        if index >= space.todos.len() {
+            return Err("Index out of bounds".to_string());
+        }
+        let old = space.todos[index].depends_on.clone();
+        self.dispatch(Action::SetDeps { space_name, index, old, new: depends_on })
+    }
 
-        
+    fn log_time(&mut self, index: usize, space_name: Option<String>, entry: TimeEntry) -> Result<(), String> {
+        let space_name = space_name.unwrap_or_else(|| "Default".to_string());
+        let space = self.current_todo_spaces.iter().find(|s| s.name == space_name)
+            .ok_or_else(|| "Space not found".to_string())?;
+        if index >= space.todos.len() {
+            return Err("Index out of bounds".to_string());
+        }
+        self.dispatch(Action::LogTime { space_name, index, entry })
     }
 
-    fn list_todos(&mut self, todo_state: StatusFilter) {
+    fn report(&self, from: Option<NaiveDate>, to: Option<NaiveDate>) {
         for space in &self.current_todo_spaces {
+            let mut space_minutes: u64 = 0;
+            let mut priority_minutes: HashMap<String, u64> = HashMap::new();
+            for todo in &space.todos {
+                for entry in &todo.time_log {
+                    if from.is_some_and(|f| entry.logged_date < f) {
+                        continue;
+                    }
+                    if to.is_some_and(|t| entry.logged_date > t) {
+                        continue;
+                    }
+                    let minutes = entry.hours as u64 * 60 + entry.minutes as u64;
+                    space_minutes += minutes;
+                    *priority_minutes.entry(format!("{:?}", todo.priority)).or_insert(0) += minutes;
+                }
+            }
             println!("=== {} ===", space.name);
-            let todos_to_display: Vec<_> = match todo_state {
+            println!("Total: {}h{}m", space_minutes / 60, space_minutes % 60);
+            for (priority, minutes) in priority_minutes {
+                println!("  {}: {}h{}m", priority, minutes / 60, minutes % 60);
+            }
+            println!();
+        }
+    }
+
+    fn list_todos(&mut self, todo_state: StatusFilter, sort: Option<SortKey>) {
+        let today = Utc::now().date_naive();
+        for space in &self.current_todo_spaces {
+            println!("=== {} ===", space.name);
+            let mut todos_to_display: Vec<_> = match todo_state {
                 StatusFilter::All => {
                     space.todos.iter().enumerate().collect()
                 },
@@ -121,11 +362,27 @@ impl TodoManager {
                 StatusFilter::Pending => {
                     space.todos.iter().enumerate().filter(|&(_, todo)| !todo.status).collect()
                 },
+                StatusFilter::Overdue => {
+                    space.todos.iter().enumerate().filter(|&(_, todo)| !todo.status && todo.due.is_some_and(|d| d < today)).collect()
+                },
+                StatusFilter::Today => {
+                    space.todos.iter().enumerate().filter(|&(_, todo)| !todo.status && todo.due == Some(today)).collect()
+                },
             };
 
+            if let Some(SortKey::Due) = sort {
+                todos_to_display.sort_by(|(_, a), (_, b)| match (a.due, b.due) {
+                    (Some(d1), Some(d2)) => d1.cmp(&d2),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                });
+            }
+
             for (i, todo) in todos_to_display {
                 let checkbox = if todo.status {"[x]"} else {"[ ]"};
-                println!("- {}: {} {} {}", i, checkbox, todo.item, todo.priority.to_markdown());
+                let due_suffix = todo.due.map(|d| format!(" (due: {})", d)).unwrap_or_default();
+                println!("- {}: {} {} {}{}", i, checkbox, todo.item, todo.priority.to_markdown(), due_suffix);
             }
             println!();
         }
@@ -134,42 +391,78 @@ impl TodoManager {
 
     fn toggle_todo(&mut self, index: usize, space_name: Option<String>) -> Result<(), String> {
         let space_name = space_name.unwrap_or_else(|| "Default".to_string());
-        if let Some(space) = self.current_todo_spaces.iter_mut().find(|s| s.name == space_name){
-            if index >= space.todos.len() {
-                return Err("Index out of bounds".to_string())
-            };
-            space.todos[index].status = !space.todos[index].status;
-            self.save_todos()?;
-            Ok(())
-        } else {
-            Err("Space not found".to_string())
+        let space = self.current_todo_spaces.iter().find(|s| s.name == space_name)
+            .ok_or_else(|| "Space not found".to_string())?;
+        if index >= space.todos.len() {
+            return Err("Index out of bounds".to_string());
         }
+        if !space.todos[index].status {
+            let unmet_deps: Vec<u64> = space.todos[index].depends_on.iter()
+                .filter(|dep_id| space.todos.iter().any(|t| t.id == **dep_id && !t.status))
+                .copied()
+                .collect();
+            if !unmet_deps.is_empty() {
+                return Err(format!("Cannot complete: depends on unfinished todo(s) {:?}", unmet_deps));
+            }
+        }
+        self.dispatch(Action::Toggle { space_name, index })
     }
-    
+
     fn delete_todo(&mut self, index: usize, space_name: Option<String>) -> Result<(), String> {
         let space_name = space_name.unwrap_or_else(|| "Default".to_string());
-        if let Some(space) = self.current_todo_spaces.iter_mut().find(|s| s.name == space_name) {
-            if index >= space.todos.len() {
-                return Err("Index out of bounds".to_string())
-            };
-            space.todos.remove(index);
-            self.save_todos()?;
-            Ok(())
-        } else {
-            Err("Space not found".to_string())
+        let space = self.current_todo_spaces.iter().find(|s| s.name == space_name)
+            .ok_or_else(|| "Space not found".to_string())?;
+        if index >= space.todos.len() {
+            return Err("Index out of bounds".to_string());
+        }
+        let removed_id = space.todos[index].id;
+        let dependents: Vec<u64> = space.todos.iter()
+            .filter(|t| t.depends_on.contains(&removed_id))
+            .map(|t| t.id)
+            .collect();
+        if !dependents.is_empty() {
+            eprintln!("Warning: todo {} is depended on by {:?}", removed_id, dependents);
+        }
+        let todo = space.todos[index].clone();
+        self.dispatch(Action::Remove { space_name, index, todo })
+    }
+
+    fn list_todos_topological(&mut self) {
+        for space in &self.current_todo_spaces {
+            println!("=== {} ===", space.name);
+            match topological_order(&space.todos) {
+                Ok(order) => {
+                    for idx in order {
+                        let todo = &space.todos[idx];
+                        let checkbox = if todo.status {"[x]"} else {"[ ]"};
+                        let blocked_suffix = if is_blocked(&space.todos, todo) { " [blocked]" } else { "" };
+                        println!("- {}: {} {} {}{}", idx, checkbox, todo.item, todo.priority.to_markdown(), blocked_suffix);
+                    }
+                },
+                Err(cycle_ids) => {
+                    println!("Error: dependency cycle detected among todo id(s) {:?}", cycle_ids);
+                }
+            }
+            println!();
         }
     }
 
     fn save_todos(&self) -> Result<(), String> {
-        let md_string = format_todos_as_markdown(&self.current_todo_spaces);
-        fs::write(&self.file_path, md_string).map_err(|e| e.to_string())?;
+        let output = match self.resolve_format() {
+            Format::Md => format_todos_as_markdown(&self.current_todo_spaces),
+            Format::Todotxt => format_todos_as_todotxt(&self.current_todo_spaces),
+        };
+        fs::write(&self.file_path, output).map_err(|e| e.to_string())?;
         Ok(())
     }
 
     fn load_todos(&mut self) -> Result<(), String> {
-        let md_string = fs::read_to_string(&self.file_path)
+        let content = fs::read_to_string(&self.file_path)
             .map_err(|e| e.to_string())?;
-        let loaded_spaces: Vec<TodoSpace> = parse_markdown_todos(md_string);
+        let loaded_spaces: Vec<TodoSpace> = match self.resolve_format() {
+            Format::Md => parse_markdown_todos(content),
+            Format::Todotxt => parse_todotxt_todos(content),
+        };
         self.current_todo_spaces = loaded_spaces;
         Ok(())
     }
@@ -195,6 +488,160 @@ impl TodoManager {
 
 // Helper fns
 
+fn is_blocked(todos: &[TodoItem], todo: &TodoItem) -> bool {
+    if todo.status {
+        return false;
+    }
+    todo.depends_on.iter().any(|dep_id| {
+        todos.iter().any(|t| t.id == *dep_id && !t.status)
+    })
+}
+
+fn topological_order(todos: &[TodoItem]) -> Result<Vec<usize>, Vec<u64>> {
+    let n = todos.len();
+    let id_to_idx: HashMap<u64, usize> = todos.iter().enumerate().map(|(i, t)| (t.id, i)).collect();
+
+    let mut in_degree = vec![0usize; n];
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (i, todo) in todos.iter().enumerate() {
+        for dep in &todo.depends_on {
+            if let Some(&dep_idx) = id_to_idx.get(dep) {
+                in_degree[i] += 1;
+                successors[dep_idx].push(i);
+            }
+        }
+    }
+
+    let mut emitted = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    for _ in 0..n {
+        // stable by current index: always pick the earliest untaken zero-in-degree todo
+        match (0..n).find(|&i| !emitted[i] && in_degree[i] == 0) {
+            Some(i) => {
+                emitted[i] = true;
+                order.push(i);
+                for &succ in &successors[i] {
+                    in_degree[succ] -= 1;
+                }
+            },
+            None => {
+                let remaining_ids = (0..n).filter(|&i| !emitted[i]).map(|i| todos[i].id).collect();
+                return Err(remaining_ids);
+            }
+        }
+    }
+    Ok(order)
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn split_amount_unit(rest: &str) -> Result<(i64, char), String> {
+    let unit = rest.chars().last().ok_or_else(|| "Empty duration".to_string())?;
+    let digits = &rest[..rest.len() - unit.len_utf8()];
+    let amount: i64 = digits.parse().map_err(|_| format!("Invalid duration '{}'", rest))?;
+    Ok((amount, unit))
+}
+
+fn resolve_due_date(raw: &str) -> Result<NaiveDate, String> {
+    let trimmed = raw.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    let today = Utc::now().date_naive();
+    let lower = trimmed.to_lowercase();
+    match lower.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + Duration::days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = lower.strip_prefix('+') {
+        let (amount, unit) = split_amount_unit(rest)?;
+        return apply_duration(today, amount, unit);
+    }
+    if let Some(rest) = lower.strip_prefix('-') {
+        let (amount, unit) = split_amount_unit(rest)?;
+        return apply_duration(today, -amount, unit);
+    }
+
+    let weekday_name = lower.strip_prefix("next ").unwrap_or(&lower);
+    if let Some(weekday) = parse_weekday(weekday_name) {
+        let mut candidate = today + Duration::days(1);
+        while candidate.weekday() != weekday {
+            candidate += Duration::days(1);
+        }
+        return Ok(candidate);
+    }
+
+    Err(format!("Could not parse due date '{}'", raw))
+}
+
+fn apply_duration(today: NaiveDate, amount: i64, unit: char) -> Result<NaiveDate, String> {
+    let days = match unit {
+        'd' => amount,
+        'w' => amount * 7,
+        'm' => amount * 30,
+        _ => return Err(format!("Unknown duration unit '{}'", unit)),
+    };
+    Ok(today + Duration::days(days))
+}
+
+fn parse_deps_token(raw: &str) -> Vec<u64> {
+    raw.split(',').filter_map(|id| id.parse().ok()).collect()
+}
+
+fn format_deps_token(deps: &[u64]) -> String {
+    deps.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn parse_duration_token(raw: &str) -> Option<(u16, u16)> {
+    let (hours_str, rest) = raw.split_once('h')?;
+    let minutes_str = rest.strip_suffix('m')?;
+    let hours: u16 = hours_str.parse().ok()?;
+    let minutes: u16 = minutes_str.parse().ok()?;
+    Some((hours, minutes))
+}
+
+fn parse_log_token(raw: &str) -> Option<TimeEntry> {
+    let (date_str, duration_str) = raw.split_once('/')?;
+    let logged_date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()?;
+    let (hours, minutes) = parse_duration_token(duration_str)?;
+    Some(TimeEntry { logged_date, hours, minutes })
+}
+
+fn format_log_token(entry: &TimeEntry) -> String {
+    format!("log:{}/{}h{}m", entry.logged_date, entry.hours, entry.minutes)
+}
+
+fn finalize_ids(spaces: &mut [TodoSpace]) {
+    let max_id = spaces.iter()
+        .flat_map(|s| s.todos.iter())
+        .map(|t| t.id)
+        .max()
+        .unwrap_or(0);
+    let mut next_id = max_id + 1;
+    for space in spaces.iter_mut() {
+        for todo in space.todos.iter_mut() {
+            if todo.id == 0 {
+                todo.id = next_id;
+                next_id += 1;
+            }
+        }
+    }
+}
+
 fn parse_markdown_todos(content: String) -> Vec<TodoSpace> {
     let mut spaces = Vec::new();
     let mut current_space = TodoSpace {
@@ -223,20 +670,48 @@ fn parse_markdown_todos(content: String) -> Vec<TodoSpace> {
                 trimmed.trim_start_matches("- [ ] ")
             };
             let priority = Priority::from_markdown(description);
+            let due = description
+                .split_whitespace()
+                .find_map(|token| token.strip_prefix("due:"))
+                .and_then(|raw| NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok());
+            let id = description
+                .split_whitespace()
+                .find_map(|token| token.strip_prefix("id:"))
+                .and_then(|raw| raw.parse().ok())
+                .unwrap_or(0);
+            let depends_on = description
+                .split_whitespace()
+                .find_map(|token| token.strip_prefix("deps:"))
+                .map(parse_deps_token)
+                .unwrap_or_default();
+            let time_log: Vec<TimeEntry> = description
+                .split_whitespace()
+                .filter_map(|token| token.strip_prefix("log:"))
+                .filter_map(parse_log_token)
+                .collect();
 
             let item_text = description
                 .replace("{LOW}", "")
                 .replace("{MEDIUM}", "")
                 .replace("{HIGH}", "")
                 .replace("{URGENT}", "")
-                .trim()
-                .to_string();
+                .split_whitespace()
+                .filter(|token| {
+                    !token.starts_with("due:") && !token.starts_with("id:")
+                        && !token.starts_with("deps:") && !token.starts_with("log:")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
 
             current_space.todos.push(
                 TodoItem {
+                    id,
                     item: item_text,
                     status,
                     priority,
+                    due,
+                    depends_on,
+                    time_log,
                 }
             );
         }
@@ -245,6 +720,7 @@ fn parse_markdown_todos(content: String) -> Vec<TodoSpace> {
     if !current_space.todos.is_empty() || current_space.name != "Default" {
         spaces.push(current_space)
     }
+    finalize_ids(&mut spaces);
     spaces
 }
 
@@ -256,10 +732,24 @@ fn format_todos_as_markdown(spaces: &[TodoSpace]) -> String {
         }
         for todo in space.todos.iter() {
             let checkbox = if todo.status { "[x]" } else { "[ ]" };
-            markdown_string.push_str(&format!("- {} {} {}\n", 
-                checkbox, 
-                todo.item, 
-                todo.priority.to_markdown( ))
+            let due_token = todo.due.map(|d| format!(" due:{}", d)).unwrap_or_default();
+            let id_token = format!(" id:{}", todo.id);
+            let deps_token = if todo.depends_on.is_empty() {
+                String::new()
+            } else {
+                format!(" deps:{}", format_deps_token(&todo.depends_on))
+            };
+            let log_tokens: String = todo.time_log.iter()
+                .map(|entry| format!(" {}", format_log_token(entry)))
+                .collect();
+            markdown_string.push_str(&format!("- {} {} {}{}{}{}{}\n",
+                checkbox,
+                todo.item,
+                todo.priority.to_markdown( ),
+                due_token,
+                id_token,
+                deps_token,
+                log_tokens)
             )
         }
         markdown_string.push('\n');
@@ -267,6 +757,132 @@ fn format_todos_as_markdown(spaces: &[TodoSpace]) -> String {
     markdown_string
 }
 
+fn parse_todotxt_todos(content: String) -> Vec<TodoSpace> {
+    let mut spaces: Vec<TodoSpace> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let mut rest = trimmed;
+        let status = if let Some(stripped) = rest.strip_prefix("x ") {
+            rest = stripped;
+            true
+        } else {
+            false
+        };
+
+        let mut priority = Priority::Medium;
+        if !status {
+            let bytes = rest.as_bytes();
+            if bytes.len() >= 4 && bytes[0] == b'(' && bytes[2] == b')' && bytes[3] == b' ' {
+                priority = Priority::from_todotxt_letter(bytes[1] as char);
+                rest = &rest[4..];
+            }
+        } else if let Some(letter) = rest
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix("pri:"))
+            .and_then(|raw| raw.chars().next())
+        {
+            priority = Priority::from_todotxt_letter(letter);
+        }
+
+        let space_name = rest
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix('+'))
+            .unwrap_or("Default")
+            .to_string();
+
+        let due = rest
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix("due:"))
+            .and_then(|raw| NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok());
+        let id = rest
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix("id:"))
+            .and_then(|raw| raw.parse().ok())
+            .unwrap_or(0);
+        let depends_on = rest
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix("deps:"))
+            .map(parse_deps_token)
+            .unwrap_or_default();
+        let time_log: Vec<TimeEntry> = rest
+            .split_whitespace()
+            .filter_map(|token| token.strip_prefix("log:"))
+            .filter_map(parse_log_token)
+            .collect();
+
+        let item_text = rest
+            .split_whitespace()
+            .filter(|token| {
+                *token != format!("+{}", space_name)
+                    && !token.starts_with("due:")
+                    && !token.starts_with("id:")
+                    && !token.starts_with("deps:")
+                    && !token.starts_with("log:")
+                    && !token.starts_with("pri:")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let space_idx = spaces.iter().position(|s: &TodoSpace| s.name == space_name)
+            .unwrap_or_else(|| {
+                spaces.push(TodoSpace { name: space_name.clone(), todos: Vec::new() });
+                spaces.len() - 1
+            });
+        spaces[space_idx].todos.push(TodoItem {
+            id,
+            item: item_text,
+            status,
+            priority,
+            due,
+            depends_on,
+            time_log,
+        });
+    }
+
+    finalize_ids(&mut spaces);
+    spaces
+}
+
+fn format_todos_as_todotxt(spaces: &[TodoSpace]) -> String {
+    let mut lines = Vec::new();
+    for space in spaces.iter() {
+        for todo in space.todos.iter() {
+            let mut line = String::new();
+            if todo.status {
+                line.push_str("x ");
+            } else {
+                line.push('(');
+                line.push(todo.priority.to_todotxt_letter());
+                line.push_str(") ");
+            }
+            line.push_str(&todo.item);
+            if space.name != "Default" {
+                line.push_str(&format!(" +{}", space.name));
+            }
+            if let Some(due) = todo.due {
+                line.push_str(&format!(" due:{}", due));
+            }
+            if todo.status {
+                line.push_str(&format!(" pri:{}", todo.priority.to_todotxt_letter()));
+            }
+            line.push_str(&format!(" id:{}", todo.id));
+            if !todo.depends_on.is_empty() {
+                line.push_str(&format!(" deps:{}", format_deps_token(&todo.depends_on)));
+            }
+            for entry in &todo.time_log {
+                line.push_str(&format!(" {}", format_log_token(entry)));
+            }
+            lines.push(line);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 enum ActionArgs {
     New, //unsure
@@ -277,7 +893,16 @@ enum ActionArgs {
     List, //done
     ListCompleted, //done
     ListPending, //done
+    ListOverdue, //done
+    ListToday, //done
+    ListTopo, //done
     ListSpaces, // done
+    SetDeps, //done
+    Tui, //done
+    Undo, //done
+    Redo, //done
+    Log, //done
+    Report, //done
 }
 
 
@@ -301,23 +926,62 @@ struct Cli {
     space_name: Option<String>,
 
     #[arg(short, long)]
-    filename:Option<String>
+    filename:Option<String>,
+
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    #[arg(long)]
+    due: Option<String>,
+
+    #[arg(long, value_enum)]
+    sort: Option<SortKey>,
+
+    #[arg(long, value_delimiter = ',')]
+    depends_on: Option<Vec<u64>>,
+
+    #[arg(long)]
+    hours: Option<u16>,
+
+    #[arg(long)]
+    minutes: Option<u16>,
+
+    #[arg(long)]
+    log_date: Option<String>,
+
+    #[arg(long)]
+    from: Option<String>,
+
+    #[arg(long)]
+    to: Option<String>,
 }
 
 fn main() -> Result<(), String> {
     let args = Cli::parse();
-    let config_path = env::var("XDG_CONFIG_HOME").expect("$XDH_CONFIG_PATH not set");
-    let path = config_path + "/todo" + "/todos.md";
+    let path = match &args.filename {
+        Some(filename) => filename.clone(),
+        None => {
+            let config_path = env::var("XDG_CONFIG_HOME").expect("$XDG_CONFIG_HOME not set");
+            config_path + "/todo" + "/todos.md"
+        }
+    };
 
-    let mut manager = TodoManager::new(path.to_string());
+    let mut manager = TodoManager::new(path.to_string(), args.format);
     match args.action {
         ActionArgs::Add => {
             if let Some(todo_text) = args.todo {
-                manager.add_todo(todo_text, args.space_name, args.priority)?;
+                let due = match args.due {
+                    Some(raw) => Some(resolve_due_date(&raw)?),
+                    None => None,
+                };
+                manager.add_todo(todo_text, args.space_name, args.priority, due, args.depends_on.unwrap_or_default())?;
             } else {
                 println!("Please provide todo with --todo");
             }
         },
+        ActionArgs::SetDeps => {
+            manager.set_dependencies(args.index, args.space_name, args.depends_on.unwrap_or_default())?;
+        },
         ActionArgs::Toggle => {
             manager.toggle_todo(args.index, args.space_name)?;
         },
@@ -325,24 +989,64 @@ fn main() -> Result<(), String> {
             manager.delete_todo(args.index, args.space_name)?;
         },
         ActionArgs::List => {
-            manager.list_todos(StatusFilter::All);
+            manager.list_todos(StatusFilter::All, args.sort);
         },
         ActionArgs::ListCompleted => {
-            manager.list_todos(StatusFilter::Completed);
+            manager.list_todos(StatusFilter::Completed, args.sort);
         },
         ActionArgs::ListPending => {
-            manager.list_todos(StatusFilter::Pending);
+            manager.list_todos(StatusFilter::Pending, args.sort);
+        },
+        ActionArgs::ListOverdue => {
+            manager.list_todos(StatusFilter::Overdue, args.sort);
+        },
+        ActionArgs::ListToday => {
+            manager.list_todos(StatusFilter::Today, args.sort);
+        },
+        ActionArgs::ListTopo => {
+            manager.list_todos_topological();
         },
         ActionArgs::New => {
-            let _manager = TodoManager::new(path.to_string());
+            let _manager = TodoManager::new(path.to_string(), args.format);
             println!("Todo manager initialized at: {}", path);
         },
         ActionArgs::Edit => {
-            manager.edit();
+            manager.edit()?;
         },
         ActionArgs::ListSpaces => {
-            manager.list_workspaces();
-            
+            manager.list_workspaces()?;
+        },
+        ActionArgs::Tui => {
+            tui::run(&mut manager)?;
+        },
+        ActionArgs::Undo => {
+            manager.undo()?;
+        },
+        ActionArgs::Redo => {
+            manager.redo()?;
+        },
+        ActionArgs::Log => {
+            let mut hours = args.hours.unwrap_or(0);
+            let mut minutes = args.minutes.unwrap_or(0);
+            hours += minutes / 60;
+            minutes %= 60;
+            let logged_date = match args.log_date {
+                Some(raw) => resolve_due_date(&raw)?,
+                None => Utc::now().date_naive(),
+            };
+            let entry = TimeEntry { logged_date, hours, minutes };
+            manager.log_time(args.index, args.space_name, entry)?;
+        },
+        ActionArgs::Report => {
+            let from = match args.from {
+                Some(raw) => Some(resolve_due_date(&raw)?),
+                None => None,
+            };
+            let to = match args.to {
+                Some(raw) => Some(resolve_due_date(&raw)?),
+                None => None,
+            };
+            manager.report(from, to);
         }
     }
     Ok(())
@@ -0,0 +1,198 @@
+// Interactive full-screen browser: spaces on the left, todos on the right.
+use std::io;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Terminal;
+
+use crate::{Priority, TodoManager};
+
+#[derive(PartialEq)]
+enum Pane {
+    Spaces,
+    Todos,
+}
+
+enum Mode {
+    Normal,
+    Adding,
+}
+
+pub fn run(manager: &mut TodoManager) -> Result<(), String> {
+    enable_raw_mode().map_err(|e| e.to_string())?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).map_err(|e| e.to_string())?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend).map_err(|e| e.to_string())?;
+
+    let result = run_app(&mut terminal, manager);
+
+    disable_raw_mode().map_err(|e| e.to_string())?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen).map_err(|e| e.to_string())?;
+
+    result
+}
+
+fn priority_color(priority: &Priority) -> Color {
+    match priority {
+        Priority::Urgent => Color::Red,
+        Priority::High => Color::Yellow,
+        Priority::Medium => Color::Cyan,
+        Priority::Low => Color::Gray,
+    }
+}
+
+fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, manager: &mut TodoManager) -> Result<(), String> {
+    let mut active_pane = Pane::Spaces;
+    let mut space_idx: usize = 0;
+    let mut todo_idx: usize = 0;
+    let mut mode = Mode::Normal;
+    let mut input = String::new();
+    let mut status = String::new();
+
+    loop {
+        terminal.draw(|f| {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+                .split(f.area());
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(f.area());
+
+            let space_items: Vec<ListItem> = manager.current_todo_spaces.iter().map(|space| {
+                let completed = space.todos.iter().filter(|t| t.status).count();
+                let total = space.todos.len();
+                ListItem::new(format!("{} ({}/{})", space.name, completed, total))
+            }).collect();
+            let mut space_state = ListState::default();
+            space_state.select(Some(space_idx));
+            let space_list = List::new(space_items)
+                .block(Block::default().borders(Borders::ALL).title("Spaces"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            f.render_stateful_widget(space_list, columns[0], &mut space_state);
+
+            let todo_items: Vec<ListItem> = manager.current_todo_spaces.get(space_idx)
+                .map(|space| space.todos.iter().map(|todo| {
+                    let checkbox = if todo.status { "[x]" } else { "[ ]" };
+                    ListItem::new(Line::from(vec![
+                        Span::raw(format!("{} {} ", checkbox, todo.item)),
+                        Span::styled(todo.priority.to_markdown(), Style::default().fg(priority_color(&todo.priority))),
+                    ]))
+                }).collect())
+                .unwrap_or_default();
+            let mut todo_state = ListState::default();
+            todo_state.select(Some(todo_idx));
+            let todo_list = List::new(todo_items)
+                .block(Block::default().borders(Borders::ALL).title("Todos"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            f.render_stateful_widget(todo_list, columns[1], &mut todo_state);
+
+            let footer_text = match mode {
+                Mode::Normal => format!("j/k move  tab switch  space toggle  d delete  a add  q quit  {}", status),
+                Mode::Adding => format!("new todo: {}", input),
+            };
+            f.render_widget(Paragraph::new(footer_text), rows[1]);
+        }).map_err(|e| e.to_string())?;
+
+        if !event::poll(Duration::from_millis(200)).map_err(|e| e.to_string())? {
+            continue;
+        }
+        let Event::Key(key) = event::read().map_err(|e| e.to_string())? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match mode {
+            Mode::Normal => match key.code {
+                KeyCode::Char('q') => break,
+                KeyCode::Tab => {
+                    active_pane = if active_pane == Pane::Spaces { Pane::Todos } else { Pane::Spaces };
+                },
+                KeyCode::Char('j') => match active_pane {
+                    Pane::Spaces => {
+                        if space_idx + 1 < manager.current_todo_spaces.len() {
+                            space_idx += 1;
+                            todo_idx = 0;
+                        }
+                    },
+                    Pane::Todos => {
+                        if let Some(space) = manager.current_todo_spaces.get(space_idx) {
+                            if todo_idx + 1 < space.todos.len() {
+                                todo_idx += 1;
+                            }
+                        }
+                    },
+                },
+                KeyCode::Char('k') => match active_pane {
+                    Pane::Spaces => {
+                        space_idx = space_idx.saturating_sub(1);
+                        todo_idx = 0;
+                    },
+                    Pane::Todos => {
+                        todo_idx = todo_idx.saturating_sub(1);
+                    },
+                },
+                KeyCode::Char(' ') => {
+                    if let Some(space_name) = manager.current_todo_spaces.get(space_idx).map(|s| s.name.clone()) {
+                        status = match manager.toggle_todo(todo_idx, Some(space_name)) {
+                            Ok(()) => String::new(),
+                            Err(e) => e,
+                        };
+                    }
+                },
+                KeyCode::Char('d') => {
+                    if let Some(space_name) = manager.current_todo_spaces.get(space_idx).map(|s| s.name.clone()) {
+                        match manager.delete_todo(todo_idx, Some(space_name)) {
+                            Ok(()) => {
+                                status = String::new();
+                                todo_idx = todo_idx.saturating_sub(1);
+                            },
+                            Err(e) => status = e,
+                        }
+                    }
+                },
+                KeyCode::Char('a') => {
+                    mode = Mode::Adding;
+                    input.clear();
+                },
+                _ => {}
+            },
+            Mode::Adding => match key.code {
+                KeyCode::Enter => {
+                    let space_name = manager.current_todo_spaces.get(space_idx).map(|s| s.name.clone());
+                    if !input.is_empty() {
+                        if let Err(e) = manager.add_todo(input.clone(), space_name, None, None, Vec::new()) {
+                            status = e;
+                        }
+                    }
+                    input.clear();
+                    mode = Mode::Normal;
+                },
+                KeyCode::Esc => {
+                    input.clear();
+                    mode = Mode::Normal;
+                },
+                KeyCode::Backspace => {
+                    input.pop();
+                },
+                KeyCode::Char(c) => {
+                    input.push(c);
+                },
+                _ => {}
+            },
+        }
+    }
+
+    Ok(())
+}